@@ -101,6 +101,18 @@
 //!     PgConnection::establish("postgres://localhost/diesel-sort-struct-fields").unwrap()
 //! }
 //! ```
+//!
+//! If you'd rather not declare the table and the struct separately at all, `define_model!` builds
+//! both from one column list, so they're guaranteed to correspond:
+//!
+//! ```rust,ignore
+//! diesel_sort_struct_fields::define_model! {
+//!     User {
+//!         name -> VarChar,
+//!         id -> Integer,
+//!     }
+//! }
+//! ```
 
 #![deny(unused_imports, dead_code, unused_variables, unused_must_use, missing_docs)]
 #![doc(html_root_url = "https://docs.rs/diesel-sort-struct-fields/0.1.0")]
@@ -120,6 +132,38 @@ use syn::{
 
 type Result<A, B = syn::Error> = std::result::Result<A, B>;
 
+/// Whether an attribute should sort its input in place, or merely check that
+/// the input is already declared in sorted order, leaving it untouched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Sort,
+    Check,
+}
+
+/// Parses the argument of `#[sort_fields]` / `#[sort_columns]`: either
+/// nothing, meaning [`Mode::Sort`], or `check`, meaning [`Mode::Check`].
+fn parse_mode(attr: TokenStream, macro_name: &str) -> Result<Mode> {
+    if attr.is_empty() {
+        return Ok(Mode::Sort);
+    }
+
+    let ident = parse2::<Ident>(attr.clone()).map_err(|_| {
+        syn::Error::new(
+            attr.span(),
+            format!("`{}` only supports the `check` argument", macro_name),
+        )
+    })?;
+
+    if ident == "check" {
+        Ok(Mode::Check)
+    } else {
+        Err(syn::Error::new(
+            ident.span(),
+            format!("`{}` only supports the `check` argument", macro_name),
+        ))
+    }
+}
+
 /// Sort fields in a model struct.
 ///
 /// See crate level docs for more info.
@@ -128,12 +172,17 @@ pub fn sort_fields(
     attr: proc_macro::TokenStream,
     item: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
+    let mode = match parse_mode(attr.into(), "#[sort_fields]") {
+        Ok(mode) => mode,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
     let ast = match syn::parse_macro_input::parse::<DeriveInput>(item) {
         Ok(ast) => ast,
         Err(err) => return err.to_compile_error().into(),
     };
 
-    match expand_sorted(attr.into(), ast) {
+    match expand_sorted(mode, ast) {
         Ok(out) => out.into(),
         Err(err) => err.to_compile_error().into(),
     }
@@ -147,15 +196,10 @@ pub fn sort_columns(
     attr: proc_macro::TokenStream,
     item: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
-    if !attr.is_empty() {
-        let attr: TokenStream = attr.into();
-        return syn::Error::new(
-            attr.span(),
-            "`#[sort_columns]` doesn't support any attributes",
-        )
-        .to_compile_error()
-        .into();
-    }
+    let mode = match parse_mode(attr.into(), "#[sort_columns]") {
+        Ok(mode) => mode,
+        Err(err) => return err.to_compile_error().into(),
+    };
 
     let ast = match parse::<syn::Macro>(item) {
         Ok(ast) => ast,
@@ -167,12 +211,13 @@ pub fn sort_columns(
         return sort_columns_on_wrong_item_error(ident.span()).into();
     }
 
-    match parse2::<TableDsl>(ast.tts) {
-        Ok(table_dsl) => {
-            let tokens = quote! { #table_dsl };
+    let table_dsl = match parse2::<TableDsl>(ast.tts) {
+        Ok(table_dsl) => table_dsl,
+        Err(err) => return err.to_compile_error().into(),
+    };
 
-            tokens.into()
-        }
+    match table_dsl.render(mode) {
+        Ok(tokens) => tokens.into(),
         Err(err) => err.to_compile_error().into(),
     }
 }
@@ -185,6 +230,172 @@ fn sort_columns_on_wrong_item_error(span: Span) -> TokenStream {
     .to_compile_error()
 }
 
+/// Define a `table!` and a matching `#[derive(Queryable)]` struct from a
+/// single column list, so the two can never drift out of correspondence.
+///
+/// Without a primary key clause, Diesel requires a column named `id`, same
+/// as plain `table!`. If your primary key is named differently, or is
+/// composite, declare it the same way `table!` does:
+/// `define_model! { User (uuid) { uuid -> Text, name -> Text } }`.
+///
+/// See crate level docs for more info.
+#[proc_macro]
+pub fn define_model(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = match parse::<DefineModelDsl>(item) {
+        Ok(ast) => ast,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    match ast.render() {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// `define_model! { User { id -> Integer, name -> Nullable<Text> } }`.
+///
+/// Reuses `ColumnDsl` so the column grammar (types, `#[sql_name]`, etc.) is
+/// identical to `table!`.
+#[derive(Debug)]
+struct DefineModelDsl {
+    struct_name: Ident,
+    id_columns: Option<Punctuated<Ident, Token![,]>>,
+    columns: Punctuated<ColumnDsl, Token![,]>,
+}
+
+impl Parse for DefineModelDsl {
+    fn parse(input: ParseStream) -> syn::parse::Result<Self> {
+        let struct_name = input.parse::<Ident>()?;
+
+        let id_columns = match try_parse_parens(input) {
+            Ok(inside_parens) => {
+                let id_columns = Punctuated::<Ident, Token![,]>::parse_terminated(&inside_parens)?;
+                Some(id_columns)
+            }
+            Err(_) => None,
+        };
+
+        let inside_braces;
+        syn::braced!(inside_braces in input);
+        let columns = Punctuated::<ColumnDsl, Token![,]>::parse_terminated(&inside_braces)?;
+
+        Ok(DefineModelDsl {
+            struct_name,
+            id_columns,
+            columns,
+        })
+    }
+}
+
+impl DefineModelDsl {
+    fn render(&self) -> Result<TokenStream> {
+        let struct_name = &self.struct_name;
+        let table_name = Ident::new(
+            &format!("{}s", struct_name.to_string().to_lowercase()),
+            struct_name.span(),
+        );
+
+        let id_column = if let Some(id_columns) = &self.id_columns {
+            quote! { ( #id_columns ) }
+        } else {
+            quote! {}
+        };
+
+        let columns = sort_punctuated(&self.columns, sql_name_of_column);
+
+        let table_columns = columns.iter().copied().map(table_column_tokens);
+
+        let fields = columns
+            .iter()
+            .map(|column| {
+                let name = &column.name;
+                let ty = rust_type_for_column(column)?;
+                Ok(quote! { #name: #ty })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(quote! {
+            diesel::table! {
+                #table_name #id_column {
+                    #( #table_columns )*
+                }
+            }
+
+            #[derive(Queryable)]
+            pub struct #struct_name {
+                #( #fields ),*
+            }
+        })
+    }
+}
+
+/// Renders a column the way `table!` expects it, dropping this crate's own
+/// `#[rust_type = "..."]` override along the way — it isn't part of
+/// Diesel's column grammar and would otherwise leak into the generated
+/// `table!` and fail to compile.
+fn table_column_tokens(column: &ColumnDsl) -> TokenStream {
+    let name = &column.name;
+    let ty = &column.ty;
+    let attributes = column
+        .attributes
+        .iter()
+        .filter(|attr| !attr.path.is_ident("rust_type"));
+
+    quote! {
+        #(#attributes)*
+        #name -> #ty,
+    }
+}
+
+/// The Rust type a column's tokens get mapped to in the generated
+/// `Queryable` struct: a `#[rust_type = "..."]` override on the column if
+/// present, otherwise the built-in SQL type mapping.
+fn rust_type_for_column(column: &ColumnDsl) -> Result<TokenStream> {
+    if let Some(rust_type) = find_name_override(&column.attributes, "rust_type") {
+        return syn::parse_str::<syn::Type>(&rust_type)
+            .map(|ty| quote! { #ty })
+            .map_err(|_| {
+                syn::Error::new(
+                    column.name.span(),
+                    format!("`#[rust_type = \"{}\"]` isn't a valid Rust type", rust_type),
+                )
+            });
+    }
+
+    built_in_rust_type(&column.ty).ok_or_else(|| {
+        let ty = &column.ty;
+        syn::Error::new(
+            column.name.span(),
+            format!(
+                "`define_model!` doesn't know the Rust type for `{}`; add a `#[rust_type = \"...\"]` override",
+                quote! { #ty },
+            ),
+        )
+    })
+}
+
+/// The built-in SQL type to Rust type mapping used by `define_model!`.
+fn built_in_rust_type(ty: &ColumnType) -> Option<TokenStream> {
+    let last_segment = ty.path.last().unwrap().value().to_string();
+
+    if last_segment == "Nullable" {
+        let inner = ty.generics.as_ref()?.first()?.value();
+        let inner_ty = built_in_rust_type(inner)?;
+        return Some(quote! { Option<#inner_ty> });
+    }
+
+    let rust_type = match last_segment.as_str() {
+        "Integer" | "Int4" => quote! { i32 },
+        "BigInt" | "BigSerial" | "Int8" => quote! { i64 },
+        "Text" | "VarChar" => quote! { String },
+        "Bool" => quote! { bool },
+        "Timestamptz" => quote! { chrono::DateTime<chrono::Utc> },
+        _ => return None,
+    };
+
+    Some(rust_type)
+}
+
 #[derive(Debug)]
 struct TableDsl {
     name: Ident,
@@ -227,8 +438,20 @@ impl Parse for TableDsl {
     }
 }
 
-impl ToTokens for TableDsl {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
+impl TableDsl {
+    /// Renders this `table!` back to tokens, either with its columns sorted
+    /// by SQL name, or (in [`Mode::Check`]) unchanged but only after
+    /// confirming they're already in sorted order.
+    fn render(&self, mode: Mode) -> Result<TokenStream> {
+        let columns = match mode {
+            Mode::Sort => sort_punctuated(&self.columns, sql_name_of_column),
+            Mode::Check => {
+                let columns = self.columns.iter().collect::<Vec<_>>();
+                check_sorted(&columns, sql_name_of_column, "#[sort_columns(check)]")?;
+                columns
+            }
+        };
+
         let table_name = &self.name;
         let attributes = &self.attributes;
 
@@ -239,9 +462,7 @@ impl ToTokens for TableDsl {
         };
         let use_statements = &self.use_statements;
 
-        let columns = sort_punctuated(&self.columns, |column| &column.name);
-
-        tokens.extend(quote! {
+        Ok(quote! {
             diesel::table! {
                 #(#use_statements)*
 
@@ -282,15 +503,7 @@ impl Parse for ColumnDsl {
         input.parse::<Token![-]>()?;
         input.parse::<Token![>]>()?;
 
-        let outer_ty = input.parse::<Ident>()?;
-        let ty = if input.peek(Token![<]) {
-            input.parse::<Token![<]>()?;
-            let ty = input.parse::<Ident>()?;
-            input.parse::<Token![>]>()?;
-            ColumnType::Wrapped(outer_ty, ty)
-        } else {
-            ColumnType::Bare(outer_ty)
-        };
+        let ty = input.parse::<ColumnType>()?;
 
         Ok(ColumnDsl {
             name,
@@ -300,17 +513,49 @@ impl Parse for ColumnDsl {
     }
 }
 
+/// A (possibly path-qualified) Diesel SQL type, such as `Integer`,
+/// `Nullable<VarChar>`, `diesel::sql_types::Timestamptz`, or
+/// `Array<Nullable<Text>>`.
+///
+/// Parsed recursively so arbitrarily nested generics with multiple
+/// parameters round-trip byte-for-byte through `ToTokens`.
 #[derive(Debug)]
-enum ColumnType {
-    Bare(Ident),
-    Wrapped(Ident, Ident),
+struct ColumnType {
+    path: Punctuated<Ident, Token![::]>,
+    generics: Option<Punctuated<ColumnType, Token![,]>>,
+}
+
+impl Parse for ColumnType {
+    fn parse(input: ParseStream) -> syn::parse::Result<Self> {
+        let mut path = Punctuated::new();
+        path.push_value(input.parse::<Ident>()?);
+
+        while input.peek(Token![::]) {
+            let sep = input.parse::<Token![::]>()?;
+            path.push_punct(sep);
+            path.push_value(input.parse::<Ident>()?);
+        }
+
+        let generics = if input.peek(Token![<]) {
+            input.parse::<Token![<]>()?;
+            let generics = Punctuated::<ColumnType, Token![,]>::parse_separated_nonempty(input)?;
+            input.parse::<Token![>]>()?;
+            Some(generics)
+        } else {
+            None
+        };
+
+        Ok(ColumnType { path, generics })
+    }
 }
 
 impl ToTokens for ColumnType {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        match self {
-            ColumnType::Bare(ty) => tokens.extend(quote! { #ty }),
-            ColumnType::Wrapped(constructor, ty) => tokens.extend(quote! { #constructor<#ty> }),
+        let path = &self.path;
+        tokens.extend(quote! { #path });
+
+        if let Some(generics) = &self.generics {
+            tokens.extend(quote! { < #generics > });
         }
     }
 }
@@ -323,23 +568,13 @@ fn try_parse_parens<'a>(input: ParseStream<'a>) -> syn::parse::Result<ParseBuffe
     })()
 }
 
-fn expand_sorted(
-    attr: proc_macro2::TokenStream,
-    ast: DeriveInput,
-) -> Result<proc_macro2::TokenStream> {
-    if !attr.is_empty() {
-        return Err(syn::Error::new(
-            attr.span(),
-            "`#[sort_fields]` doesn't support any attributes",
-        ));
-    }
-
+fn expand_sorted(mode: Mode, ast: DeriveInput) -> Result<proc_macro2::TokenStream> {
     let attrs = ast.attrs;
     let vis = ast.vis;
     let ident = ast.ident;
     let generics = ast.generics;
 
-    let sorted_fieds = find_and_sort_struct_fields(&ast.data, ident.span())?;
+    let sorted_fieds = find_and_order_struct_fields(&ast.data, ident.span(), mode)?;
 
     let tokens = quote! {
         #(#attrs)*
@@ -353,7 +588,7 @@ fn expand_sorted(
 
 fn sort_punctuated<A, B, F, K>(punctuated: &Punctuated<A, B>, f: F) -> Vec<&A>
 where
-    F: Fn(&A) -> &K,
+    F: Fn(&A) -> K,
     K: Ord,
 {
     let mut items = punctuated.iter().collect::<Vec<_>>();
@@ -361,13 +596,119 @@ where
     items
 }
 
-fn find_and_sort_struct_fields(data: &syn::Data, ident_span: Span) -> Result<Vec<&syn::Field>> {
+/// The name this column is known by in the database, so the `table!` sorts
+/// in lockstep with the struct even when a column is renamed with
+/// `#[sql_name = "..."]`.
+fn sql_name_of_column(column: &ColumnDsl) -> String {
+    find_name_override(&column.attributes, "sql_name").unwrap_or_else(|| column.name.to_string())
+}
+
+/// The name this field is known by in the database, so the struct sorts in
+/// lockstep with the `table!` even when a field is renamed with
+/// `#[diesel(column_name = "...")]`.
+fn sql_name_of_field(field: &syn::Field) -> String {
+    find_diesel_column_name_override(&field.attrs)
+        .unwrap_or_else(|| field.ident.as_ref().unwrap().to_string())
+}
+
+/// Looks for `#[$attr_name = "..."]` among `attrs` and returns the string it
+/// was set to, if present.
+fn find_name_override(attrs: &[syn::Attribute], attr_name: &str) -> Option<String> {
+    attrs.iter().find_map(|attr| {
+        if !attr.path.is_ident(attr_name) {
+            return None;
+        }
+
+        match attr.parse_meta().ok()? {
+            syn::Meta::NameValue(name_value) => match name_value.lit {
+                syn::Lit::Str(s) => Some(s.value()),
+                _ => None,
+            },
+            _ => None,
+        }
+    })
+}
+
+/// Looks for `#[diesel(column_name = "...")]` among `attrs` and returns the
+/// string it was set to, if present.
+fn find_diesel_column_name_override(attrs: &[syn::Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| {
+        if !attr.path.is_ident("diesel") {
+            return None;
+        }
+
+        let meta_list = match attr.parse_meta().ok()? {
+            syn::Meta::List(meta_list) => meta_list,
+            _ => return None,
+        };
+
+        meta_list.nested.iter().find_map(|nested| match nested {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+                if name_value.path.is_ident("column_name") =>
+            {
+                match &name_value.lit {
+                    syn::Lit::Str(s) => Some(s.value()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+    })
+}
+
+/// Checks that `items`, ordered by `key`, are already in sorted order,
+/// without changing that order. On mismatch, errors out on the span of the
+/// first out-of-place item, listing the expected order.
+fn check_sorted<A, K, F>(items: &[&A], key: F, macro_name: &str) -> Result<()>
+where
+    F: Fn(&A) -> K,
+    K: Ord + Clone + std::fmt::Display,
+    A: ToTokens,
+{
+    let keys = items.iter().map(|item| key(item)).collect::<Vec<_>>();
+    let mut sorted_keys = keys.clone();
+    sorted_keys.sort();
+
+    if keys == sorted_keys {
+        return Ok(());
+    }
+
+    let offending_index = keys
+        .iter()
+        .zip(&sorted_keys)
+        .position(|(actual, expected)| actual != expected)
+        .unwrap_or(0);
+
+    let expected = sorted_keys
+        .iter()
+        .map(|key| key.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Err(syn::Error::new(
+        items[offending_index].span(),
+        format!(
+            "`{}` expects these to already be declared in sorted order: {}",
+            macro_name, expected
+        ),
+    ))
+}
+
+fn find_and_order_struct_fields(
+    data: &syn::Data,
+    ident_span: Span,
+    mode: Mode,
+) -> Result<Vec<&syn::Field>> {
     match data {
         syn::Data::Struct(data_struct) => match &data_struct.fields {
-            syn::Fields::Named(fields) => {
-                let fields = sort_punctuated(&fields.named, |field| &field.ident);
-                Ok(fields)
-            }
+            syn::Fields::Named(fields) => match mode {
+                Mode::Sort => Ok(sort_punctuated(&fields.named, sql_name_of_field)),
+                Mode::Check => {
+                    let fields = fields.named.iter().collect::<Vec<_>>();
+                    check_sorted(&fields, sql_name_of_field, "#[sort_fields(check)]")?;
+                    Ok(fields)
+                }
+            },
             syn::Fields::Unnamed(fields) => Err(syn::Error::new(
                 fields.span(),
                 "`#[sort_fields]` is not allowed on tuple structs, only structs with named fields",
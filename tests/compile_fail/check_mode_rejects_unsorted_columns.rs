@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel_sort_struct_fields::sort_columns;
+
+#[sort_columns(check)]
+table! {
+    users (id) {
+        name -> VarChar,
+        id -> Integer,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::prelude::*;
+use diesel::PgConnection;
+use diesel_sort_struct_fields::define_model;
+
+define_model! {
+    User (uuid) {
+        name -> VarChar,
+        #[rust_type = "String"]
+        bio -> Text,
+        uuid -> diesel::sql_types::Text,
+    }
+}
+
+fn loading_users() {
+    let db = connect_to_db();
+    let _: Vec<User> = users::table
+        .select(users::all_columns)
+        .load::<User>(&db)
+        .unwrap();
+}
+
+fn connect_to_db() -> PgConnection {
+    unimplemented!()
+}
+
+fn main() {}
@@ -0,0 +1,46 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::prelude::*;
+use diesel::PgConnection;
+use diesel_sort_struct_fields::{sort_columns, sort_fields};
+
+// In the struct, the Rust idents (`display_name`, `email`) are already in
+// order, but the real database column names (`b_display_name`, `a_email`)
+// sort the other way around; in the table it's the reverse. `#[sort_columns]` and `#[sort_fields]` must agree on the
+// latter, not the former.
+#[sort_columns]
+table! {
+    users (id) {
+        id -> Integer,
+        #[sql_name = "a_email"]
+        email -> Text,
+        #[sql_name = "b_display_name"]
+        display_name -> Text,
+    }
+}
+
+#[sort_fields]
+#[derive(Queryable, Debug)]
+pub struct User {
+    id: i32,
+    #[diesel(column_name = "b_display_name")]
+    display_name: String,
+    #[diesel(column_name = "a_email")]
+    email: String,
+}
+
+fn loading_users() {
+    let db = connect_to_db();
+    let users: Vec<User> = users::table
+        .select(users::all_columns)
+        .load::<User>(&db)
+        .unwrap();
+    dbg!(users);
+}
+
+fn connect_to_db() -> PgConnection {
+    unimplemented!()
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::prelude::*;
+use diesel::PgConnection;
+use diesel_sort_struct_fields::sort_columns;
+
+#[sort_columns]
+table! {
+    users (id) {
+        name -> diesel::sql_types::VarChar,
+        tags -> Array<Nullable<Text>>,
+        friends_count -> Nullable<diesel::sql_types::Integer>,
+        id -> Integer,
+    }
+}
+
+#[derive(Queryable, Debug)]
+pub struct User {
+    friends_count: Option<i32>,
+    id: i32,
+    name: String,
+    tags: Vec<Option<String>>,
+}
+
+fn loading_users() {
+    let db = connect_to_db();
+    let users: Vec<User> = users::table
+        .select(users::all_columns)
+        .load::<User>(&db)
+        .unwrap();
+    dbg!(users);
+}
+
+fn connect_to_db() -> PgConnection {
+    unimplemented!()
+}
+
+fn main() {}